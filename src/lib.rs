@@ -1,8 +1,10 @@
 use std::marker::PhantomData;
+use std::sync::Arc;
+use std::time::{Duration, UNIX_EPOCH};
 
 use axum::body::{boxed, Body, Full, HttpBody};
 use axum::handler::HandlerWithoutStateExt;
-use axum::http::{header, StatusCode, Uri};
+use axum::http::{header, HeaderMap, StatusCode, Uri};
 use axum::response::{IntoResponse, Response};
 use axum::routing::get_service;
 use axum::Router;
@@ -15,10 +17,82 @@ where
     A: RustEmbed,
 {
     path: &'static str,
+    config: SpaConfig,
     _assets: PhantomData<A>,
     _marker: PhantomData<fn() -> (B, T, S)>,
 }
 
+/// Runtime configuration threaded through `assets_handler`/`serve_asset`,
+/// shared cheaply between the requests serviced by a single `SpaRouter`.
+#[derive(Clone)]
+struct SpaConfig {
+    index_file: &'static str,
+    strict_asset_prefixes: Vec<&'static str>,
+    not_found: Arc<dyn Fn() -> Response + Send + Sync>,
+    asset_cache_control: Option<CacheControl>,
+    index_cache_control: Option<CacheControl>,
+}
+
+impl Default for SpaConfig {
+    fn default() -> Self {
+        Self {
+            index_file: INDEX_PATH,
+            strict_asset_prefixes: Vec::new(),
+            not_found: Arc::new(not_found),
+            asset_cache_control: None,
+            index_cache_control: None,
+        }
+    }
+}
+
+/// A `Cache-Control` policy for assets served by a `SpaRouter`, set via
+/// [`SpaRouter::asset_cache_control`] / [`SpaRouter::index_cache_control`].
+#[derive(Debug, Clone, Copy)]
+pub enum CacheControl {
+    /// `Cache-Control: no-cache` — always revalidate with the server.
+    NoCache,
+    /// `Cache-Control: public, max-age=<max_age>`, plus `immutable` when set.
+    /// Use `immutable` for content-hashed assets that never change once
+    /// baked, so clients skip revalidation entirely for the given duration.
+    MaxAge { max_age: u32, immutable: bool },
+}
+
+impl CacheControl {
+    /// A `public, max-age=<max_age>` policy with `immutable` unset.
+    pub fn max_age(max_age: u32) -> Self {
+        Self::MaxAge {
+            max_age,
+            immutable: false,
+        }
+    }
+
+    /// Marks a [`CacheControl::MaxAge`] policy as `immutable`; a no-op on
+    /// [`CacheControl::NoCache`].
+    pub fn immutable(self) -> Self {
+        match self {
+            Self::MaxAge { max_age, .. } => Self::MaxAge {
+                max_age,
+                immutable: true,
+            },
+            Self::NoCache => self,
+        }
+    }
+
+    fn header_value(self) -> String {
+        match self {
+            Self::NoCache => "no-cache".to_owned(),
+            Self::MaxAge {
+                max_age,
+                immutable: true,
+            } => format!("public, max-age={max_age}, immutable"),
+            Self::MaxAge {
+                max_age,
+                immutable: false,
+            } => format!("public, max-age={max_age}"),
+        }
+    }
+}
+
 impl<A, B, T, S> SpaRouter<A, B, T, S>
 where
     A: RustEmbed + 'static,
@@ -26,10 +100,54 @@ where
     pub fn new(path: &'static str) -> Self {
         Self {
             path,
+            config: SpaConfig::default(),
             _assets: Default::default(),
             _marker: Default::default(),
         }
     }
+
+    /// Sets the embedded file served as the SPA document, in place of the
+    /// default `index.html`.
+    pub fn index_file(mut self, index_file: &'static str) -> Self {
+        self.config.index_file = index_file;
+        self
+    }
+
+    /// Marks `prefix` as a strict asset path: a request under the mount
+    /// whose path starts with `prefix` but isn't baked in returns the
+    /// not-found response instead of falling back to the index document.
+    /// Client-side routes that don't match any configured prefix (e.g.
+    /// `/dashboard`) keep falling back to the SPA document. Call this once
+    /// per prefix to register more than one (e.g. `"assets/"`, `"static/"`).
+    pub fn serve_assets_strictly(mut self, prefix: &'static str) -> Self {
+        self.config.strict_asset_prefixes.push(prefix);
+        self
+    }
+
+    /// Overrides the response returned for an asset under a
+    /// [`serve_assets_strictly`](Self::serve_assets_strictly) prefix that
+    /// doesn't exist.
+    pub fn not_found_response<R>(mut self, response: R) -> Self
+    where
+        R: IntoResponse + Clone + Send + Sync + 'static,
+    {
+        self.config.not_found = Arc::new(move || response.clone().into_response());
+        self
+    }
+
+    /// Sets the `Cache-Control` policy emitted for regular assets. Unset by
+    /// default, so no `Cache-Control` header is sent.
+    pub fn asset_cache_control(mut self, policy: CacheControl) -> Self {
+        self.config.asset_cache_control = Some(policy);
+        self
+    }
+
+    /// Sets the `Cache-Control` policy emitted for the served index
+    /// document. Unset by default, so no `Cache-Control` header is sent.
+    pub fn index_cache_control(mut self, policy: CacheControl) -> Self {
+        self.config.index_cache_control = Some(policy);
+        self
+    }
 }
 
 impl<A, B, T, S> From<SpaRouter<A, B, T, S>> for Router<S, B>
@@ -40,37 +158,193 @@ where
     S: Clone + Send + Sync + 'static,
 {
     fn from(spa: SpaRouter<A, B, T, S>) -> Self {
+        let config = spa.config;
+        let assets_config = config.clone();
+        let index_config = config;
+
         Router::new()
-            .nest_service(spa.path, get_service(assets_handler::<A>.into_service()))
-            .fallback_service(get_service(serve_index::<A>.into_service()))
-    }
-}
-async fn serve_asset<A: RustEmbed>(path: &str) -> Response {
-    if let Some(index) = A::get(path).or_else(|| A::get(INDEX_PATH)) {
-        let body = boxed(Full::from(index.data));
-        let mime = mime_guess::from_path(path).first_or_octet_stream();
-        let etag = base64::encode(index.metadata.sha256_hash());
-        Response::builder()
-            .header(header::CONTENT_TYPE, mime.as_ref())
-            .header(header::ETAG, etag)
-            .body(body)
-            .unwrap_or_else(|_| not_found())
+            .nest_service(
+                spa.path,
+                get_service(
+                    (move |uri: Uri, headers: HeaderMap| {
+                        let config = assets_config.clone();
+                        async move { assets_handler::<A>(uri, headers, config).await }
+                    })
+                    .into_service(),
+                ),
+            )
+            .fallback_service(get_service(
+                (move |headers: HeaderMap| {
+                    let config = index_config.clone();
+                    async move { serve_index::<A>(headers, config).await }
+                })
+                .into_service(),
+            ))
+    }
+}
+
+async fn serve_asset<A: RustEmbed>(
+    path: &str,
+    headers: &HeaderMap,
+    config: &SpaConfig,
+) -> Response {
+    let (logical_path, index) = match A::get(path) {
+        Some(file) => (path, file),
+        None => {
+            let is_strict_asset_path = config
+                .strict_asset_prefixes
+                .iter()
+                .any(|prefix| path.starts_with(prefix));
+            if is_strict_asset_path {
+                return (config.not_found)();
+            }
+            match A::get(config.index_file) {
+                Some(file) => (config.index_file, file),
+                None => return (config.not_found)(),
+            }
+        }
+    };
+
+    let etag = base64::encode(index.metadata.sha256_hash());
+    let last_modified = index.metadata.last_modified().map(last_modified_header);
+    let cache_control = if logical_path == config.index_file {
+        config.index_cache_control
     } else {
-        not_found()
+        config.asset_cache_control
+    };
+
+    let has_precompressed_variant = has_precompressed_variant::<A>(logical_path);
+
+    if is_not_modified(headers, &etag, last_modified.as_deref()) {
+        let mut builder = Response::builder()
+            .status(StatusCode::NOT_MODIFIED)
+            .header(header::ETAG, etag);
+        if let Some(last_modified) = last_modified {
+            builder = builder.header(header::LAST_MODIFIED, last_modified);
+        }
+        if let Some(cache_control) = cache_control {
+            builder = builder.header(header::CACHE_CONTROL, cache_control.header_value());
+        }
+        if has_precompressed_variant {
+            builder = builder.header(header::VARY, header::ACCEPT_ENCODING.as_str());
+        }
+        return builder
+            .body(boxed(Full::default()))
+            .unwrap_or_else(|_| not_found());
+    }
+
+    let encoded = select_encoded_variant::<A>(logical_path, headers);
+    let mime = mime_guess::from_path(logical_path).first_or_octet_stream();
+
+    let mut builder = Response::builder()
+        .header(header::CONTENT_TYPE, mime.as_ref())
+        .header(header::ETAG, etag);
+    if let Some(last_modified) = last_modified {
+        builder = builder.header(header::LAST_MODIFIED, last_modified);
+    }
+    if let Some(cache_control) = cache_control {
+        builder = builder.header(header::CACHE_CONTROL, cache_control.header_value());
     }
+    if has_precompressed_variant {
+        builder = builder.header(header::VARY, header::ACCEPT_ENCODING.as_str());
+    }
+
+    let body = match encoded {
+        Some((encoding, file)) => {
+            builder = builder.header(header::CONTENT_ENCODING, encoding);
+            boxed(Full::from(file.data))
+        }
+        None => boxed(Full::from(index.data)),
+    };
+
+    builder.body(body).unwrap_or_else(|_| not_found())
+}
+
+/// Returns true if `path` has a baked `.br` or `.gz` sibling, regardless of
+/// whether the current request's `Accept-Encoding` selects it — used to
+/// decide whether `Vary: Accept-Encoding` applies to this resource at all.
+fn has_precompressed_variant<A: RustEmbed>(path: &str) -> bool {
+    A::get(&format!("{path}.br")).is_some() || A::get(&format!("{path}.gz")).is_some()
+}
+
+/// Picks the best precompressed variant of `path` that the client's
+/// `Accept-Encoding` header allows, preferring `br` over `gzip`, and falls
+/// back to `None` when no precompressed sibling was baked in.
+fn select_encoded_variant<A: RustEmbed>(
+    path: &str,
+    headers: &HeaderMap,
+) -> Option<(&'static str, rust_embed::EmbeddedFile)> {
+    if accepts_encoding(headers, "br") {
+        if let Some(file) = A::get(&format!("{path}.br")) {
+            return Some(("br", file));
+        }
+    }
+
+    if accepts_encoding(headers, "gzip") {
+        if let Some(file) = A::get(&format!("{path}.gz")) {
+            return Some(("gzip", file));
+        }
+    }
+
+    None
+}
+
+/// Returns true if `encoding` appears as one of the comma-separated codings
+/// in the request's `Accept-Encoding` header (qvalues are ignored).
+fn accepts_encoding(headers: &HeaderMap, encoding: &str) -> bool {
+    headers
+        .get(header::ACCEPT_ENCODING)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| {
+            value
+                .split(',')
+                .any(|coding| coding.split(';').next().unwrap_or("").trim() == encoding)
+        })
+        .unwrap_or(false)
+}
+
+/// Formats a `rust_embed` last-modified timestamp (seconds since the epoch)
+/// as an HTTP-date suitable for the `Last-Modified` header.
+fn last_modified_header(timestamp: u64) -> String {
+    httpdate::fmt_http_date(UNIX_EPOCH + Duration::from_secs(timestamp))
+}
+
+/// Returns true if the request's `If-None-Match` (preferred) or
+/// `If-Modified-Since` header indicates the client's cached copy is current.
+fn is_not_modified(headers: &HeaderMap, etag: &str, last_modified: Option<&str>) -> bool {
+    if let Some(if_none_match) = headers.get(header::IF_NONE_MATCH) {
+        return if_none_match.as_bytes() == etag.as_bytes();
+    }
+
+    if let (Some(if_modified_since), Some(last_modified)) =
+        (headers.get(header::IF_MODIFIED_SINCE), last_modified)
+    {
+        let if_modified_since = if_modified_since
+            .to_str()
+            .ok()
+            .and_then(|v| httpdate::parse_http_date(v).ok());
+        let last_modified = httpdate::parse_http_date(last_modified).ok();
+
+        if let (Some(if_modified_since), Some(last_modified)) = (if_modified_since, last_modified) {
+            return last_modified <= if_modified_since;
+        }
+    }
+
+    false
 }
 
-async fn assets_handler<A: RustEmbed>(uri: Uri) -> Response {
+async fn assets_handler<A: RustEmbed>(uri: Uri, headers: HeaderMap, config: SpaConfig) -> Response {
     if uri.path() == "/" {
-        serve_index::<A>().await
+        serve_index::<A>(headers, config).await
     } else {
         let path = uri.path().trim_start_matches('/');
-        serve_asset::<A>(path).await
+        serve_asset::<A>(path, &headers, &config).await
     }
 }
 
-async fn serve_index<A: RustEmbed>() -> Response {
-    serve_asset::<A>(INDEX_PATH).await
+async fn serve_index<A: RustEmbed>(headers: HeaderMap, config: SpaConfig) -> Response {
+    let index_file = config.index_file;
+    serve_asset::<A>(index_file, &headers, &config).await
 }
 
 fn not_found() -> Response {
@@ -94,7 +368,7 @@ mod tests {
 
     #[tokio::test]
     async fn rust_embed_as_file_provider() {
-        let resp = serve_index::<TestAssets>().await;
+        let resp = serve_index::<TestAssets>(HeaderMap::new(), SpaConfig::default()).await;
         assert_eq!(200, resp.status())
     }
 
@@ -185,4 +459,160 @@ mod tests {
         assert_eq!(res.status(), StatusCode::OK);
         assert_eq!(res.text().await, "OK");
     }
+
+    #[tokio::test]
+    async fn conditional_get_returns_not_modified() {
+        let app = Router::new().merge(SpaRouter::new("/") as SpaRouter<TestAssets>);
+        let client = TestClient::new(app);
+
+        let res = client.get("/").send().await;
+        let etag = res.headers().get(header::ETAG).unwrap().clone();
+
+        let res = client
+            .get("/")
+            .header(header::IF_NONE_MATCH, etag.clone())
+            .send()
+            .await;
+        assert_eq!(res.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(res.headers().get(header::ETAG).unwrap(), &etag);
+        assert_eq!(res.text().await, "");
+
+        let res = client
+            .get("/")
+            .header(header::IF_NONE_MATCH, "\"not-the-etag\"")
+            .send()
+            .await;
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let last_modified = client
+            .get("/")
+            .send()
+            .await
+            .headers()
+            .get(header::LAST_MODIFIED)
+            .cloned();
+        if let Some(last_modified) = last_modified {
+            let res = client
+                .get("/")
+                .header(header::IF_MODIFIED_SINCE, last_modified)
+                .send()
+                .await;
+            assert_eq!(res.status(), StatusCode::NOT_MODIFIED);
+        }
+    }
+
+    #[tokio::test]
+    async fn serves_precompressed_variant_when_accepted() {
+        let app = Router::new().merge(SpaRouter::new("/") as SpaRouter<TestAssets>);
+        let client = TestClient::new(app);
+
+        let res = client
+            .get("/assets/script.js")
+            .header(header::ACCEPT_ENCODING, "br, gzip")
+            .send()
+            .await;
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.headers().get(header::CONTENT_ENCODING).unwrap(), "br");
+        assert_eq!(
+            res.headers().get(header::VARY).unwrap(),
+            header::ACCEPT_ENCODING.as_str()
+        );
+        assert_eq!(
+            res.headers().get(header::CONTENT_TYPE).unwrap().as_bytes(),
+            b"application/javascript"
+        );
+
+        let res = client
+            .get("/assets/script.js")
+            .header(header::ACCEPT_ENCODING, "gzip")
+            .send()
+            .await;
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.headers().get(header::CONTENT_ENCODING).unwrap(), "gzip");
+
+        let res = client.get("/assets/script.js").send().await;
+        assert_eq!(res.status(), StatusCode::OK);
+        assert!(res.headers().get(header::CONTENT_ENCODING).is_none());
+        assert_eq!(res.text().await, "console.log('hi')\n");
+    }
+
+    #[tokio::test]
+    async fn not_modified_response_repeats_vary() {
+        let app = Router::new().merge(SpaRouter::new("/") as SpaRouter<TestAssets>);
+        let client = TestClient::new(app);
+
+        let res = client
+            .get("/assets/script.js")
+            .header(header::ACCEPT_ENCODING, "br")
+            .send()
+            .await;
+        let etag = res.headers().get(header::ETAG).unwrap().clone();
+        assert_eq!(
+            res.headers().get(header::VARY).unwrap(),
+            header::ACCEPT_ENCODING.as_str()
+        );
+
+        let res = client
+            .get("/assets/script.js")
+            .header(header::ACCEPT_ENCODING, "br")
+            .header(header::IF_NONE_MATCH, etag)
+            .send()
+            .await;
+        assert_eq!(res.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(
+            res.headers().get(header::VARY).unwrap(),
+            header::ACCEPT_ENCODING.as_str()
+        );
+    }
+
+    #[tokio::test]
+    async fn strict_asset_serving_returns_real_404() {
+        let app = Router::new().merge(
+            SpaRouter::new("/")
+                .serve_assets_strictly("assets/")
+                .not_found_response((StatusCode::NOT_FOUND, "no such asset"))
+                as SpaRouter<TestAssets>,
+        );
+        let client = TestClient::new(app);
+
+        // Unknown path under the strict asset prefix is a genuine 404, not the SPA shell.
+        let res = client.get("/assets/doesnt_exist").send().await;
+        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+        assert_eq!(res.text().await, "no such asset");
+
+        // Known assets and the index document are unaffected.
+        let res = client.get("/assets/script.js").send().await;
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let res = client.get("/").send().await;
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.text().await, "<h1>Hello, World!</h1>\n");
+
+        // A client-side route outside the strict prefix still falls back to the index.
+        let res = client.get("/dashboard").send().await;
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.text().await, "<h1>Hello, World!</h1>\n");
+    }
+
+    #[tokio::test]
+    async fn cache_control_differs_for_assets_and_index() {
+        let app = Router::new().merge(
+            SpaRouter::new("/")
+                .asset_cache_control(CacheControl::max_age(31536000).immutable())
+                .index_cache_control(CacheControl::NoCache) as SpaRouter<TestAssets>,
+        );
+        let client = TestClient::new(app);
+
+        let res = client.get("/assets/script.js").send().await;
+        assert_eq!(
+            res.headers().get(header::CACHE_CONTROL).unwrap(),
+            "public, max-age=31536000, immutable"
+        );
+
+        let res = client.get("/").send().await;
+        assert_eq!(
+            res.headers().get(header::CACHE_CONTROL).unwrap(),
+            "no-cache"
+        );
+    }
 }